@@ -1,15 +1,192 @@
 use sway_error::error::CompileError;
-use sway_error::warning::CompileWarning;
+use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{Span, Spanned};
 
+use sway_types::integer_bits::IntegerBits;
+
 use crate::{
     decl_engine::DeclId,
     engine_threading::*,
     error::*,
-    language::ty::{self, TyConstantDecl, TyFunctionDecl},
+    language::{
+        ty::{self, TyConstantDecl, TyFunctionDecl},
+        CallPath, LazyOp, Literal,
+    },
     type_system::*,
 };
 
+/// Why a [Divergence::Always] node is known to never fall through to the
+/// node that follows it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DivergenceReason {
+    Return,
+    Break,
+    Continue,
+    InfiniteLoop,
+}
+
+/// A small three-state lattice describing whether control flow can fall
+/// through a given `TyAstNode`/`TyExpression`.
+#[derive(Clone, Debug)]
+enum Divergence {
+    Maybe,
+    Always(Span, DivergenceReason),
+}
+
+impl Divergence {
+    fn is_always(&self) -> bool {
+        matches!(self, Divergence::Always(..))
+    }
+}
+
+fn node_divergence(node: &ty::TyAstNode) -> Divergence {
+    match &node.content {
+        ty::TyAstNodeContent::Expression(expr)
+        | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => expr_divergence(expr),
+        // Mirrors `codeblock_contains_reachable_break`'s `VariableDecl` case:
+        // a `let` initializer can itself diverge (e.g. `let x = return 5;`),
+        // and subsequent statements are unreachable when it does.
+        ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(decl)) => {
+            expr_divergence(&decl.body)
+        }
+        ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect(_) => {
+            Divergence::Maybe
+        }
+    }
+}
+
+fn codeblock_divergence(cb: &ty::TyCodeBlock) -> Divergence {
+    for node in &cb.contents {
+        let divergence = node_divergence(node);
+        if divergence.is_always() {
+            return divergence;
+        }
+    }
+    Divergence::Maybe
+}
+
+fn expr_divergence(expr: &ty::TyExpression) -> Divergence {
+    match &expr.expression {
+        ty::TyExpressionVariant::Return(_) => {
+            Divergence::Always(expr.span.clone(), DivergenceReason::Return)
+        }
+        ty::TyExpressionVariant::Break => {
+            Divergence::Always(expr.span.clone(), DivergenceReason::Break)
+        }
+        ty::TyExpressionVariant::Continue => {
+            Divergence::Always(expr.span.clone(), DivergenceReason::Continue)
+        }
+        ty::TyExpressionVariant::CodeBlock(cb) => codeblock_divergence(cb),
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => expr_divergence(desugared),
+        ty::TyExpressionVariant::IfExp { then, r#else, .. } => {
+            let then_div = expr_divergence(then);
+            if !then_div.is_always() {
+                return Divergence::Maybe;
+            }
+            match r#else {
+                Some(r#else) if expr_divergence(r#else).is_always() => then_div,
+                _ => Divergence::Maybe,
+            }
+        }
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            if is_literal_true(condition) && !codeblock_contains_reachable_break(body) {
+                Divergence::Always(expr.span.clone(), DivergenceReason::InfiniteLoop)
+            } else {
+                Divergence::Maybe
+            }
+        }
+        _ => Divergence::Maybe,
+    }
+}
+
+fn is_literal_true(expr: &ty::TyExpression) -> bool {
+    matches!(
+        &expr.expression,
+        ty::TyExpressionVariant::Literal(Literal::Boolean(true))
+    )
+}
+
+fn codeblock_contains_reachable_break(cb: &ty::TyCodeBlock) -> bool {
+    cb.contents.iter().any(|node| match &node.content {
+        ty::TyAstNodeContent::Expression(expr)
+        | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => {
+            expr_contains_reachable_break(expr)
+        }
+        ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(decl)) => {
+            expr_contains_reachable_break(&decl.body)
+        }
+        ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect(_) => false,
+    })
+}
+
+/// Mirrors the shape of `expr_validate`'s traversal so every expression kind
+/// that can nest a `break` (not just the control-flow-shaped ones) is
+/// visited.
+fn expr_contains_reachable_break(expr: &ty::TyExpression) -> bool {
+    match &expr.expression {
+        ty::TyExpressionVariant::Break => true,
+        ty::TyExpressionVariant::Literal(_)
+        | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::FunctionParameter
+        | ty::TyExpressionVariant::AsmExpression { .. }
+        | ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AbiName(_)
+        | ty::TyExpressionVariant::Continue => false,
+        ty::TyExpressionVariant::FunctionApplication { arguments, .. } => {
+            arguments.iter().any(|f| expr_contains_reachable_break(&f.1))
+        }
+        ty::TyExpressionVariant::LazyOperator {
+            lhs: expr1,
+            rhs: expr2,
+            ..
+        }
+        | ty::TyExpressionVariant::ArrayIndex {
+            prefix: expr1,
+            index: expr2,
+        } => expr_contains_reachable_break(expr1) || expr_contains_reachable_break(expr2),
+        ty::TyExpressionVariant::IntrinsicFunction(ty::TyIntrinsicFunctionKind {
+            arguments: exprvec,
+            ..
+        })
+        | ty::TyExpressionVariant::Tuple { fields: exprvec }
+        | ty::TyExpressionVariant::Array {
+            elem_type: _,
+            contents: exprvec,
+        } => exprvec.iter().any(expr_contains_reachable_break),
+        ty::TyExpressionVariant::StructExpression { fields, .. } => fields
+            .iter()
+            .any(|f| expr_contains_reachable_break(&f.value)),
+        ty::TyExpressionVariant::CodeBlock(cb) => codeblock_contains_reachable_break(cb),
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => {
+            expr_contains_reachable_break(desugared)
+        }
+        ty::TyExpressionVariant::IfExp { condition, then, r#else } => {
+            expr_contains_reachable_break(condition)
+                || expr_contains_reachable_break(then)
+                || r#else
+                    .as_ref()
+                    .map_or(false, |e| expr_contains_reachable_break(e))
+        }
+        ty::TyExpressionVariant::StructFieldAccess { prefix: exp, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
+        | ty::TyExpressionVariant::AbiCast { address: exp, .. }
+        | ty::TyExpressionVariant::EnumTag { exp }
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Return(exp) => expr_contains_reachable_break(exp),
+        ty::TyExpressionVariant::EnumInstantiation { contents, .. } => contents
+            .as_ref()
+            .map_or(false, |f| expr_contains_reachable_break(f)),
+        // A `break` inside a nested loop targets that loop, not this one.
+        ty::TyExpressionVariant::WhileLoop { .. } => false,
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            expr_contains_reachable_break(&reassignment.rhs)
+        }
+        ty::TyExpressionVariant::StorageReassignment(storage_reassignment) => {
+            expr_contains_reachable_break(&storage_reassignment.rhs)
+        }
+    }
+}
+
 fn ast_node_validate(engines: Engines<'_>, x: &ty::TyAstNodeContent) -> CompileResult<()> {
     let errors: Vec<CompileError> = vec![];
     let warnings: Vec<CompileWarning> = vec![];
@@ -25,8 +202,15 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
     let mut errors: Vec<CompileError> = vec![];
     let mut warnings: Vec<CompileWarning> = vec![];
     match &expr.expression {
-        ty::TyExpressionVariant::Literal(_)
-        | ty::TyExpressionVariant::VariableExpression { .. }
+        ty::TyExpressionVariant::Literal(lit) => {
+            check!(
+                check_literal_range(engines, lit, expr.return_type, &expr.span),
+                (),
+                warnings,
+                errors
+            );
+        }
+        ty::TyExpressionVariant::VariableExpression { .. }
         | ty::TyExpressionVariant::FunctionParameter
         | ty::TyExpressionVariant::AsmExpression { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
@@ -62,6 +246,19 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
             }
         }
         ty::TyExpressionVariant::StructExpression { fields, .. } => {
+            // The type arguments used here must satisfy the struct's declared bounds.
+            if let Ok(TypeInfo::Struct(decl_ref)) =
+                engines.te().to_typeinfo(expr.return_type, &expr.span)
+            {
+                let type_parameters = engines.de().get_struct(decl_ref.id()).type_parameters;
+                let mut seen = std::collections::HashSet::new();
+                check!(
+                    check_trait_bounds(engines, &type_parameters, expr.span.clone(), &mut seen),
+                    (),
+                    warnings,
+                    errors
+                );
+            }
             for f in fields {
                 check!(expr_validate(engines, &f.value), continue, warnings, errors);
             }
@@ -141,6 +338,76 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
     ok((), warnings, errors)
 }
 
+/// For an integer/byte literal, confirms its magnitude fits within the
+/// width of its inferred type. `Numeric` (still-unconstrained) types are
+/// deferred rather than rejected, since a later inference pass may yet
+/// narrow them.
+fn check_literal_range(
+    engines: Engines<'_>,
+    lit: &Literal,
+    return_type: TypeId,
+    span: &Span,
+) -> CompileResult<()> {
+    let mut warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+
+    let type_info = check!(
+        CompileResult::from(
+            engines
+                .te()
+                .to_typeinfo(return_type, span)
+                .map_err(CompileError::from)
+        ),
+        TypeInfo::ErrorRecovery,
+        warnings,
+        errors
+    );
+
+    let max = match type_info {
+        TypeInfo::UnsignedInteger(bits) => max_for_bits(bits),
+        _ => None,
+    };
+
+    let is_unsigned_target = matches!(type_info, TypeInfo::UnsignedInteger(_));
+    if is_unsigned_target && span.as_str().trim_start().starts_with('-') {
+        warnings.push(CompileWarning {
+            span: span.clone(),
+            warning_content: Warning::NegativeLiteralForUnsignedType {
+                ty: engines.help_out(type_info.clone()).to_string(),
+            },
+        });
+    }
+
+    if let Some(max) = max {
+        if let Some(value) = literal_as_i128(lit) {
+            if value < 0 || value as u128 > max as u128 {
+                errors.push(CompileError::IntegerLiteralOutOfRange {
+                    value,
+                    ty: engines.help_out(type_info).to_string(),
+                    span: span.clone(),
+                });
+            }
+        }
+    }
+
+    ok((), warnings, errors)
+}
+
+/// The largest value representable by an unsigned integer of the given
+/// width, or `None` if that width can't be range-checked by this pass.
+/// u256 is deliberately excluded: a u256 literal can exceed both `u64` and
+/// `literal_as_i128`'s `i128`, so comparing against it would need
+/// big-integer arithmetic this pass doesn't have.
+fn max_for_bits(bits: IntegerBits) -> Option<u64> {
+    match bits {
+        IntegerBits::Eight => Some(u8::MAX as u64),
+        IntegerBits::Sixteen => Some(u16::MAX as u64),
+        IntegerBits::ThirtyTwo => Some(u32::MAX as u64),
+        IntegerBits::SixtyFour => Some(u64::MAX),
+        IntegerBits::V256 => None,
+    }
+}
+
 fn check_type(
     engines: Engines<'_>,
     ty: TypeId,
@@ -174,6 +441,223 @@ fn check_type(
     ok((), warnings, errors)
 }
 
+/// A node in the "contains-by-value" graph walked by [check_for_recursive_type].
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum TypeNode {
+    Struct(DeclId<ty::TyStructDecl>),
+    Enum(DeclId<ty::TyEnumDecl>),
+}
+
+fn type_node_fields(engines: Engines<'_>, node: &TypeNode) -> Vec<(TypeId, Span)> {
+    let decl_engine = engines.de();
+    match node {
+        TypeNode::Struct(decl_id) => decl_engine
+            .get_struct(decl_id)
+            .fields
+            .iter()
+            .map(|f| (f.type_argument.type_id, f.span.clone()))
+            .collect(),
+        TypeNode::Enum(decl_id) => decl_engine
+            .get_enum(decl_id)
+            .variants
+            .iter()
+            .map(|v| (v.type_argument.type_id, v.span.clone()))
+            .collect(),
+    }
+}
+
+/// Resolves `type_id`, descending through tuples and fixed-size arrays, and
+/// collects the struct/enum nodes it contains by value. References, raw
+/// pointers and generic type parameters are not descended into.
+fn collect_value_type_nodes(
+    engines: Engines<'_>,
+    type_id: TypeId,
+    span: &Span,
+    out: &mut Vec<(TypeNode, Span)>,
+) {
+    let type_info = match engines.te().to_typeinfo(type_id, span) {
+        Ok(type_info) => type_info,
+        Err(_) => return,
+    };
+    match type_info {
+        TypeInfo::ErrorRecovery => (),
+        TypeInfo::Struct(decl_ref) => {
+            out.push((TypeNode::Struct(decl_ref.id().clone()), span.clone()))
+        }
+        TypeInfo::Enum(decl_ref) => out.push((TypeNode::Enum(decl_ref.id().clone()), span.clone())),
+        TypeInfo::Tuple(fields) => {
+            for field in fields {
+                collect_value_type_nodes(engines, field.type_id, span, out);
+            }
+        }
+        TypeInfo::Array(elem_ty, _) => {
+            collect_value_type_nodes(engines, elem_ty.type_id, span, out);
+        }
+        _ => (),
+    }
+}
+
+/// Depth-first search over the contains-by-value graph rooted at `node`,
+/// looking for a back-edge to a node already on the current path. Nodes that
+/// are fully explored without finding a cycle are memoized in `done` so one
+/// search expands each node of a large type graph at most once;
+/// [check_for_recursive_type] starts every search with an empty `done`.
+fn find_cycle(
+    engines: Engines<'_>,
+    node: TypeNode,
+    span: Span,
+    stack: &mut Vec<(TypeNode, Span)>,
+    done: &mut std::collections::HashSet<TypeNode>,
+) -> Option<Vec<(TypeNode, Span)>> {
+    if let Some(pos) = stack.iter().position(|(n, _)| *n == node) {
+        // `span` is the edge that closes the loop back to `stack[pos]`; push
+        // it too so callers see the full cycle (including the field that
+        // actually closes it) instead of stopping one edge short.
+        let mut cycle = stack[pos..].to_vec();
+        cycle.push((node, span));
+        return Some(cycle);
+    }
+    if done.contains(&node) {
+        return None;
+    }
+    stack.push((node.clone(), span));
+    let mut children = vec![];
+    for (field_ty, field_span) in type_node_fields(engines, &node) {
+        collect_value_type_nodes(engines, field_ty, &field_span, &mut children);
+    }
+    for (child, child_span) in children {
+        if let Some(cycle) = find_cycle(engines, child, child_span, stack, done) {
+            return Some(cycle);
+        }
+    }
+    stack.pop();
+    done.insert(node);
+    None
+}
+
+fn check_for_recursive_type(
+    engines: Engines<'_>,
+    root: TypeNode,
+    decl_span: Span,
+) -> CompileResult<()> {
+    let warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+    let mut stack = vec![];
+    let mut done = std::collections::HashSet::new();
+    if let Some(cycle) = find_cycle(engines, root.clone(), decl_span.clone(), &mut stack, &mut done)
+    {
+        // `find_cycle` returns the first cycle found anywhere in the subgraph
+        // reachable from `root`; only report it against `root` if `root`
+        // itself is on that cycle. Otherwise it's a cycle among `root`'s
+        // dependencies, which already gets (and will get) its own error when
+        // `decl_validate` runs on those declarations directly.
+        if cycle.iter().any(|(n, _)| *n == root) {
+            let back_edge_span = cycle
+                .last()
+                .map(|(_, span)| span.clone())
+                .unwrap_or_else(|| decl_span.clone());
+            errors.push(CompileError::RecursiveType {
+                name: decl_span.as_str().to_string(),
+                span: decl_span,
+                back_edge_span,
+            });
+        }
+    }
+    ok((), warnings, errors)
+}
+
+/// Checks that `type_id`, and every type nested within it (the same way
+/// [check_type]'s `extract_nested_types` walks storage-only types), actually
+/// satisfies each trait in `constraints`. Already-checked (type, trait name)
+/// pairs, keyed by the type's display name, are skipped via `seen` so each
+/// such pair is only solved for once per `seen` set.
+fn check_type_argument_bounds(
+    engines: Engines<'_>,
+    type_id: TypeId,
+    constraints: &[TraitConstraint],
+    constraint_span: Span,
+    usage_span: Span,
+    seen: &mut std::collections::HashSet<(String, String)>,
+) -> CompileResult<()> {
+    let mut warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+
+    if constraints.is_empty() {
+        return ok((), warnings, errors);
+    }
+
+    let type_info = check!(
+        CompileResult::from(
+            engines
+                .te()
+                .to_typeinfo(type_id, &usage_span)
+                .map_err(CompileError::from)
+        ),
+        TypeInfo::ErrorRecovery,
+        warnings,
+        errors
+    );
+    if matches!(
+        type_info,
+        TypeInfo::ErrorRecovery | TypeInfo::GenericTypeForFunctionScope { .. }
+    ) {
+        return ok((), warnings, errors);
+    }
+
+    for nested in type_info.extract_nested_types(engines) {
+        // Dedup by the *nested* type being constrained (e.g. `T` inside
+        // `Vec<T>`), not by the outer `type_id` passed in, which is the same
+        // for every nested type and would skip every nested type after the
+        // first one checked.
+        let nested_key = engines.help_out(nested.clone()).to_string();
+        for constraint in constraints {
+            let trait_name = constraint.trait_name.suffix.to_string();
+            if !seen.insert((nested_key.clone(), trait_name.clone())) {
+                continue;
+            }
+            if !engines
+                .de()
+                .is_trait_implemented_for_type(engines, &nested, &trait_name)
+            {
+                errors.push(CompileError::TraitConstraintNotSatisfied {
+                    ty: engines.help_out(nested.clone()).to_string(),
+                    trait_name,
+                    constraint_span: constraint_span.clone(),
+                    usage_span: usage_span.clone(),
+                });
+            }
+        }
+    }
+
+    ok((), warnings, errors)
+}
+
+fn check_trait_bounds(
+    engines: Engines<'_>,
+    type_parameters: &[TypeParameter],
+    usage_span: Span,
+    seen: &mut std::collections::HashSet<(String, String)>,
+) -> CompileResult<()> {
+    let mut warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+    for tp in type_parameters {
+        check!(
+            check_type_argument_bounds(
+                engines,
+                tp.type_id,
+                &tp.trait_constraints,
+                tp.name_ident.span(),
+                usage_span.clone(),
+                seen,
+            ),
+            continue,
+            warnings,
+            errors
+        );
+    }
+    ok((), warnings, errors)
+}
+
 fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
     let mut warnings: Vec<CompileWarning> = vec![];
     let mut errors: Vec<CompileError> = vec![];
@@ -189,7 +673,7 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
             check!(expr_validate(engines, &decl.body), (), warnings, errors)
         }
         ty::TyDecl::ConstantDecl { decl_id, .. } => {
-            check!(
+            let _folded_value = check!(
                 validate_const_decl(engines, decl_id),
                 return err(warnings, errors),
                 warnings,
@@ -208,7 +692,23 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
             // These methods are not typed. They are however handled from ImplTrait.
         }
         ty::TyDecl::ImplTrait { decl_id, .. } => {
-            let ty::TyImplTrait { items, .. } = decl_engine.get_impl_trait(decl_id);
+            let ty::TyImplTrait {
+                items,
+                impl_type_parameters,
+                span,
+                ..
+            } = decl_engine.get_impl_trait(decl_id);
+            check!(
+                check_trait_bounds(
+                    engines,
+                    &impl_type_parameters,
+                    span,
+                    &mut std::collections::HashSet::new(),
+                ),
+                (),
+                warnings,
+                errors
+            );
             for item in items {
                 match item {
                     ty::TyImplItem::Fn(decl_ref) => {
@@ -220,7 +720,7 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
                         );
                     }
                     ty::TyImplItem::Constant(decl_ref) => {
-                        check!(
+                        let _folded_value = check!(
                             validate_const_decl(engines, decl_ref.id()),
                             return err(warnings, errors),
                             warnings,
@@ -231,8 +731,24 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
             }
         }
         ty::TyDecl::StructDecl { decl_id, .. } => {
-            let ty::TyStructDecl { fields, .. } = decl_engine.get_struct(decl_id);
-            for field in fields {
+            let ty::TyStructDecl {
+                fields,
+                call_path,
+                type_parameters,
+                ..
+            } = decl_engine.get_struct(decl_id);
+            check!(
+                check_trait_bounds(
+                    engines,
+                    &type_parameters,
+                    call_path.suffix.span(),
+                    &mut std::collections::HashSet::new(),
+                ),
+                (),
+                warnings,
+                errors
+            );
+            for field in &fields {
                 check!(
                     check_type(
                         engines,
@@ -245,10 +761,36 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
                     errors
                 );
             }
+            check!(
+                check_for_recursive_type(
+                    engines,
+                    TypeNode::Struct(decl_id.clone()),
+                    call_path.suffix.span(),
+                ),
+                (),
+                warnings,
+                errors
+            );
         }
         ty::TyDecl::EnumDecl { decl_id, .. } => {
-            let ty::TyEnumDecl { variants, .. } = decl_engine.get_enum(decl_id);
-            for variant in variants {
+            let ty::TyEnumDecl {
+                variants,
+                call_path,
+                type_parameters,
+                ..
+            } = decl_engine.get_enum(decl_id);
+            check!(
+                check_trait_bounds(
+                    engines,
+                    &type_parameters,
+                    call_path.suffix.span(),
+                    &mut std::collections::HashSet::new(),
+                ),
+                (),
+                warnings,
+                errors
+            );
+            for variant in &variants {
                 check!(
                     check_type(
                         engines,
@@ -261,6 +803,16 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
                     errors
                 );
             }
+            check!(
+                check_for_recursive_type(
+                    engines,
+                    TypeNode::Enum(decl_id.clone()),
+                    call_path.suffix.span(),
+                ),
+                (),
+                warnings,
+                errors
+            );
         }
         ty::TyDecl::EnumVariantDecl {
             decl_id,
@@ -323,10 +875,334 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDecl) -> CompileResult<()> {
     }
 }
 
+/// The result of folding a `TyExpression` that is required to be evaluable
+/// at compile time.
+#[derive(Clone, Debug)]
+enum ConstValue {
+    Literal(Literal),
+    Tuple(Vec<ConstValue>),
+    Array(Vec<ConstValue>),
+    Struct(Vec<(String, ConstValue)>),
+}
+
+fn literal_as_i128(lit: &Literal) -> Option<i128> {
+    match lit {
+        Literal::U8(v) => Some(*v as i128),
+        Literal::U16(v) => Some(*v as i128),
+        Literal::U32(v) => Some(*v as i128),
+        Literal::U64(v) => Some(*v as i128),
+        Literal::Numeric(v) => Some(*v as i128),
+        // A u256 value can exceed i128::MAX, so it can't be round-tripped
+        // through this representation. Treat it as non-const rather than
+        // silently truncating it; folding u256 arithmetic needs a
+        // big-integer representation this pass doesn't have yet.
+        Literal::U256(_) => None,
+        _ => None,
+    }
+}
+
+fn int_fits_width(value: i128, bits: IntegerBits) -> bool {
+    if value < 0 {
+        return false;
+    }
+    match bits {
+        IntegerBits::Eight => value <= u8::MAX as i128,
+        IntegerBits::Sixteen => value <= u16::MAX as i128,
+        IntegerBits::ThirtyTwo => value <= u32::MAX as i128,
+        IntegerBits::SixtyFour => value <= u64::MAX as i128,
+        // Every value that reaches here already fits in an `i128`, which is
+        // well within u256 range, so this can't overflow. It does NOT mean
+        // the result can be folded into a `Literal` — see `int_to_literal`.
+        IntegerBits::V256 => true,
+    }
+}
+
+/// Folds `value` into a `Literal` of the given width, or `None` if `bits`
+/// isn't representable by this pass's `Literal` arithmetic (currently u256:
+/// there is no `Literal::U256(i128)` constructor this code can produce
+/// without silently mislabeling the result as a narrower type).
+fn int_to_literal(value: i128, bits: IntegerBits) -> Option<Literal> {
+    match bits {
+        IntegerBits::Eight => Some(Literal::U8(value as u8)),
+        IntegerBits::Sixteen => Some(Literal::U16(value as u16)),
+        IntegerBits::ThirtyTwo => Some(Literal::U32(value as u32)),
+        IntegerBits::SixtyFour => Some(Literal::U64(value as u64)),
+        IntegerBits::V256 => None,
+    }
+}
+
+fn integer_width(engines: Engines<'_>, type_id: TypeId, span: &Span) -> Option<IntegerBits> {
+    match engines.te().to_typeinfo(type_id, span).ok()? {
+        TypeInfo::UnsignedInteger(bits) => Some(bits),
+        _ => None,
+    }
+}
+
+/// `core::ops` methods that may appear in a const-evaluated expression;
+/// anything else calling into non-const code is rejected.
+const CONST_EVAL_ARITHMETIC_METHODS: &[&str] = &["add", "subtract", "multiply", "divide"];
+
+/// Returns the arithmetic method name iff `call_path` resolves into
+/// `core::ops` itself, rather than merely sharing a method name with it. A
+/// user-defined function named e.g. `add` must not be folded as if it were
+/// `core::ops::Add::add`.
+fn core_ops_arithmetic_method(call_path: &CallPath) -> Option<&'static str> {
+    let in_core_ops = call_path
+        .prefixes
+        .iter()
+        .any(|ident| ident.as_str() == "core")
+        && call_path
+            .prefixes
+            .iter()
+            .any(|ident| ident.as_str() == "ops");
+    if !in_core_ops {
+        return None;
+    }
+    CONST_EVAL_ARITHMETIC_METHODS
+        .iter()
+        .copied()
+        .find(|&m| m == call_path.suffix.as_str())
+}
+
+fn const_eval_arithmetic(
+    engines: Engines<'_>,
+    expr: &ty::TyExpression,
+    method: &str,
+    args: &[ConstValue],
+) -> CompileResult<ConstValue> {
+    let mut warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+    let ints: Option<Vec<i128>> = args
+        .iter()
+        .map(|v| match v {
+            ConstValue::Literal(lit) => literal_as_i128(lit),
+            _ => None,
+        })
+        .collect();
+    let ints = match ints {
+        Some(ints) => ints,
+        None => {
+            errors.push(CompileError::NonConstantInitializer {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+    };
+    let (a, b) = match ints.as_slice() {
+        [a, b] => (*a, *b),
+        _ => {
+            errors.push(CompileError::NonConstantInitializer {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+    };
+    let raw = match method {
+        "add" => a.checked_add(b),
+        "subtract" => a.checked_sub(b),
+        "multiply" => a.checked_mul(b),
+        "divide" => {
+            if b == 0 {
+                errors.push(CompileError::ConstEvalDivideByZero {
+                    span: expr.span.clone(),
+                });
+                return err(warnings, errors);
+            }
+            a.checked_div(b)
+        }
+        _ => None,
+    };
+    let value = match raw {
+        Some(value) => value,
+        None => {
+            errors.push(CompileError::ConstEvalOverflow {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+    };
+    let bits = integer_width(engines, expr.return_type, &expr.span).unwrap_or(IntegerBits::SixtyFour);
+    if !int_fits_width(value, bits) {
+        errors.push(CompileError::ConstEvalOverflow {
+            span: expr.span.clone(),
+        });
+        return err(warnings, errors);
+    }
+    let literal = match int_to_literal(value, bits) {
+        Some(literal) => literal,
+        None => {
+            // u256 folding needs big-integer arithmetic this pass doesn't
+            // have; don't pretend to fold it into a truncated u64 literal.
+            errors.push(CompileError::NonConstantInitializer {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+    };
+    ok(ConstValue::Literal(literal), warnings, errors)
+}
+
+fn const_eval(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult<ConstValue> {
+    let mut warnings: Vec<CompileWarning> = vec![];
+    let mut errors: Vec<CompileError> = vec![];
+    let value = match &expr.expression {
+        ty::TyExpressionVariant::Literal(lit) => ConstValue::Literal(lit.clone()),
+        ty::TyExpressionVariant::Tuple { fields } => {
+            let mut values = vec![];
+            for f in fields {
+                values.push(check!(
+                    const_eval(engines, f),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+            }
+            ConstValue::Tuple(values)
+        }
+        ty::TyExpressionVariant::Array { contents, .. } => {
+            let mut values = vec![];
+            for f in contents {
+                values.push(check!(
+                    const_eval(engines, f),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+            }
+            ConstValue::Array(values)
+        }
+        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+            let mut values = vec![];
+            for f in fields {
+                let value = check!(
+                    const_eval(engines, &f.value),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                values.push((f.name.to_string(), value));
+            }
+            ConstValue::Struct(values)
+        }
+        ty::TyExpressionVariant::LazyOperator { op, lhs, rhs } => {
+            let lhs = check!(
+                const_eval(engines, lhs),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            let rhs = check!(
+                const_eval(engines, rhs),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            match (lhs, rhs) {
+                (ConstValue::Literal(Literal::Boolean(l)), ConstValue::Literal(Literal::Boolean(r))) => {
+                    ConstValue::Literal(Literal::Boolean(match op {
+                        LazyOp::And => l && r,
+                        LazyOp::Or => l || r,
+                    }))
+                }
+                _ => {
+                    errors.push(CompileError::NonConstantInitializer {
+                        span: expr.span.clone(),
+                    });
+                    return err(warnings, errors);
+                }
+            }
+        }
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => {
+            if !matches!(kind.kind, sway_ast::intrinsics::Intrinsic::Eq) {
+                errors.push(CompileError::NonConstantInitializer {
+                    span: expr.span.clone(),
+                });
+                return err(warnings, errors);
+            }
+            let mut args = vec![];
+            for a in &kind.arguments {
+                args.push(check!(
+                    const_eval(engines, a),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+            }
+            let ints: Option<Vec<i128>> = args
+                .iter()
+                .map(|v| match v {
+                    ConstValue::Literal(lit) => literal_as_i128(lit),
+                    _ => None,
+                })
+                .collect();
+            match ints.as_deref() {
+                Some([a, b]) => ConstValue::Literal(Literal::Boolean(a == b)),
+                _ => {
+                    errors.push(CompileError::NonConstantInitializer {
+                        span: expr.span.clone(),
+                    });
+                    return err(warnings, errors);
+                }
+            }
+        }
+        ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            call_path,
+            ..
+        } => {
+            let method = match core_ops_arithmetic_method(call_path) {
+                Some(method) => method,
+                None => {
+                    errors.push(CompileError::NonConstantInitializer {
+                        span: expr.span.clone(),
+                    });
+                    return err(warnings, errors);
+                }
+            };
+            let mut args = vec![];
+            for (_, a) in arguments {
+                args.push(check!(
+                    const_eval(engines, a),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+            }
+            check!(
+                const_eval_arithmetic(engines, expr, method, &args),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+        }
+        ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AsmExpression { .. }
+        | ty::TyExpressionVariant::WhileLoop { .. }
+        | ty::TyExpressionVariant::Reassignment(_) => {
+            errors.push(CompileError::NonConstantInitializer {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+        _ => {
+            errors.push(CompileError::NonConstantInitializer {
+                span: expr.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+    };
+    ok(value, warnings, errors)
+}
+
+/// Validates `decl_id`'s initializer and, if it folds to a compile-time
+/// constant, returns that folded [Literal] so callers can reuse it (e.g. for
+/// const-folding array lengths or match patterns) instead of re-evaluating
+/// the initializer from scratch. Tuple, array and struct constants are
+/// validated but return `None`, as does a declaration with no initializer.
 pub fn validate_const_decl(
     engines: Engines<'_>,
     decl_id: &DeclId<TyConstantDecl>,
-) -> CompileResult<()> {
+) -> CompileResult<Option<Literal>> {
     let mut warnings: Vec<CompileWarning> = vec![];
     let mut errors: Vec<CompileError> = vec![];
     let decl_engine = engines.de();
@@ -335,6 +1211,7 @@ pub fn validate_const_decl(
         call_path,
         ..
     } = decl_engine.get_constant(decl_id);
+    let mut folded = None;
     if let Some(expr) = expr {
         check!(
             check_type(engines, expr.return_type, call_path.suffix.span(), false),
@@ -342,10 +1219,20 @@ pub fn validate_const_decl(
             warnings,
             errors
         );
-        check!(expr_validate(engines, &expr), (), warnings, errors)
+        check!(expr_validate(engines, &expr), (), warnings, errors);
+        let value = check!(
+            const_eval(engines, &expr),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        folded = match value {
+            ConstValue::Literal(lit) => Some(lit),
+            _ => None,
+        };
     }
     if errors.is_empty() {
-        ok((), warnings, errors)
+        ok(folded, warnings, errors)
     } else {
         err(warnings, errors)
     }
@@ -362,8 +1249,21 @@ pub fn validate_fn_decl(
         body,
         parameters,
         return_type,
+        type_parameters,
+        name,
         ..
     } = decl_engine.get_function(decl_id);
+    check!(
+        check_trait_bounds(
+            engines,
+            &type_parameters,
+            name.span(),
+            &mut std::collections::HashSet::new(),
+        ),
+        (),
+        warnings,
+        errors
+    );
     check!(
         validate_decls_for_storage_only_types_in_codeblock(engines, &body),
         (),
@@ -394,6 +1294,69 @@ pub fn validate_fn_decl(
     ok((), warnings, errors)
 }
 
+#[cfg(test)]
+mod const_eval_tests {
+    use super::*;
+
+    #[test]
+    fn literal_as_i128_covers_all_unsigned_widths() {
+        assert_eq!(literal_as_i128(&Literal::U8(8)), Some(8));
+        assert_eq!(literal_as_i128(&Literal::U16(16)), Some(16));
+        assert_eq!(literal_as_i128(&Literal::U32(32)), Some(32));
+        assert_eq!(literal_as_i128(&Literal::U64(64)), Some(64));
+    }
+
+    #[test]
+    fn literal_as_i128_rejects_u256_rather_than_truncating() {
+        // u256 can exceed i128::MAX; folding it through this representation
+        // would silently truncate the value, so it must come back `None`.
+        assert_eq!(literal_as_i128(&Literal::U256(Default::default())), None);
+    }
+
+    #[test]
+    fn int_fits_width_rejects_negative_and_over_max() {
+        assert!(int_fits_width(255, IntegerBits::Eight));
+        assert!(!int_fits_width(256, IntegerBits::Eight));
+        assert!(!int_fits_width(-1, IntegerBits::Eight));
+        assert!(int_fits_width(u64::MAX as i128, IntegerBits::SixtyFour));
+    }
+
+    #[test]
+    fn int_to_literal_round_trips_non_u256_widths() {
+        assert!(matches!(
+            int_to_literal(8, IntegerBits::Eight),
+            Some(Literal::U8(8))
+        ));
+        assert!(matches!(
+            int_to_literal(64, IntegerBits::SixtyFour),
+            Some(Literal::U64(64))
+        ));
+    }
+
+    #[test]
+    fn int_to_literal_refuses_to_fold_u256() {
+        // Must not silently alias to a truncated `Literal::U64` the way this
+        // used to before u256 got its own (unsupported) arm.
+        assert_eq!(int_to_literal(1, IntegerBits::V256), None);
+    }
+
+    #[test]
+    fn max_for_bits_covers_every_unsigned_width() {
+        assert_eq!(max_for_bits(IntegerBits::Eight), Some(u8::MAX as u64));
+        assert_eq!(max_for_bits(IntegerBits::Sixteen), Some(u16::MAX as u64));
+        assert_eq!(max_for_bits(IntegerBits::ThirtyTwo), Some(u32::MAX as u64));
+        assert_eq!(max_for_bits(IntegerBits::SixtyFour), Some(u64::MAX));
+    }
+
+    #[test]
+    fn max_for_bits_defers_on_u256_instead_of_silently_skipping_the_check() {
+        // Before this had its own arm, V256 fell into the same `_ => None`
+        // as an unrelated `TypeInfo`, so a u256 overflow went unnoticed by
+        // accident rather than by a documented decision.
+        assert_eq!(max_for_bits(IntegerBits::V256), None);
+    }
+}
+
 pub fn validate_decls_for_storage_only_types_in_ast(
     engines: Engines<'_>,
     ast_n: &ty::TyAstNodeContent,
@@ -407,13 +1370,25 @@ pub fn validate_decls_for_storage_only_types_in_codeblock(
 ) -> CompileResult<()> {
     let mut warnings: Vec<CompileWarning> = vec![];
     let mut errors: Vec<CompileError> = vec![];
+    let mut divergence = Divergence::Maybe;
     for x in &cb.contents {
+        if let Divergence::Always(cause_span, _) = &divergence {
+            warnings.push(CompileWarning {
+                span: x.span.clone(),
+                warning_content: Warning::UnreachableCode {
+                    cause_span: cause_span.clone(),
+                },
+            });
+        }
         check!(
             ast_node_validate(engines, &x.content),
             continue,
             warnings,
             errors
-        )
+        );
+        if !divergence.is_always() {
+            divergence = node_divergence(x);
+        }
     }
     ok((), warnings, errors)
 }
\ No newline at end of file
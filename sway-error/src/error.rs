@@ -0,0 +1,70 @@
+use sway_types::Span;
+
+#[derive(Debug, Clone)]
+pub enum CompileError {
+    InvalidStorageOnlyTypeDecl {
+        ty: String,
+        span: Span,
+    },
+    RecursiveType {
+        name: String,
+        span: Span,
+        back_edge_span: Span,
+    },
+    NonConstantInitializer {
+        span: Span,
+    },
+    ConstEvalDivideByZero {
+        span: Span,
+    },
+    ConstEvalOverflow {
+        span: Span,
+    },
+    IntegerLiteralOutOfRange {
+        value: i128,
+        ty: String,
+        span: Span,
+    },
+    TraitConstraintNotSatisfied {
+        ty: String,
+        trait_name: String,
+        constraint_span: Span,
+        usage_span: Span,
+    },
+}
+
+impl std::fmt::Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompileError::InvalidStorageOnlyTypeDecl { ty, .. } => {
+                write!(f, "Type \"{ty}\" can only be used in storage.")
+            }
+            CompileError::RecursiveType { name, .. } => {
+                write!(
+                    f,
+                    "Recursive type \"{name}\" has infinite size; insert an indirection (e.g. a reference or a box) to break the cycle."
+                )
+            }
+            CompileError::NonConstantInitializer { .. } => {
+                write!(f, "This initializer is not a compile-time constant.")
+            }
+            CompileError::ConstEvalDivideByZero { .. } => {
+                write!(f, "Division by zero in a compile-time constant expression.")
+            }
+            CompileError::ConstEvalOverflow { .. } => {
+                write!(
+                    f,
+                    "This compile-time constant expression overflows its declared integer type."
+                )
+            }
+            CompileError::IntegerLiteralOutOfRange { value, ty, .. } => {
+                write!(f, "Literal value {value} does not fit in type \"{ty}\".")
+            }
+            CompileError::TraitConstraintNotSatisfied {
+                ty, trait_name, ..
+            } => {
+                write!(f, "Type \"{ty}\" does not satisfy the trait constraint \"{trait_name}\".")
+            }
+        }
+    }
+}
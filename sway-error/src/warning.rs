@@ -0,0 +1,32 @@
+use sway_types::{Span, Spanned};
+
+#[derive(Debug, Clone)]
+pub struct CompileWarning {
+    pub span: Span,
+    pub warning_content: Warning,
+}
+
+impl Spanned for CompileWarning {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Warning {
+    UnreachableCode { cause_span: Span },
+    NegativeLiteralForUnsignedType { ty: String },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::UnreachableCode { .. } => {
+                write!(f, "This code is unreachable and will never be executed.")
+            }
+            Warning::NegativeLiteralForUnsignedType { ty } => {
+                write!(f, "A negative literal is being used where type \"{ty}\" is expected.")
+            }
+        }
+    }
+}